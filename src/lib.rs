@@ -75,8 +75,8 @@ assert_eq!(
 use once_cell::sync::OnceCell;
 
 thread_local!(
-    static TRUE_VALUES: OnceCell<Vec<String>> = OnceCell::new();
-    static FALSE_VALUES: OnceCell<Vec<String>> = OnceCell::new();
+    static TRUE_VALUES: OnceCell<Vec<String>> = const { OnceCell::new() };
+    static FALSE_VALUES: OnceCell<Vec<String>> = const { OnceCell::new() };
 );
 
 /// Intialize a custom set of truth-y values
@@ -103,6 +103,388 @@ pub fn initialize_false_values<S: ToString>(values: impl IntoIterator<Item = S>)
     FALSE_VALUES.with(|f| f.set(values).is_ok())
 }
 
+/// A reusable, instance-based parser for [`LexicalBool`](./struct.LexicalBool.html)
+///
+/// Unlike the thread-local [`initialize_true_values`](./fn.initialize_true_values.html) /
+/// [`initialize_false_values`](./fn.initialize_false_values.html) path, a `LexicalBoolParser`
+/// owns its own vocabularies, so you can build as many independent parsers as you like with
+/// different truthy/falsey words for different inputs.
+///
+/// ```rust
+/// # use lexical_bool::LexicalBoolParser;
+/// let parser = LexicalBoolParser::new()
+///     .with_true_values(["foo", "bar"])
+///     .with_false_values(["baz", "qux"]);
+///
+/// assert_eq!(parser.parse("foo").unwrap(), true);
+/// assert_eq!(parser.parse("QUX").unwrap(), false);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LexicalBoolParser {
+    true_values: Vec<String>,
+    false_values: Vec<String>,
+    case_insensitive: bool,
+    mode: ParseMode,
+    numeric: bool,
+}
+
+/// Ready-made value sets for common real-world vocabularies
+///
+/// Use with [`LexicalBoolParser::with_preset`](./struct.LexicalBoolParser.html#method.with_preset).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Preset {
+    /// HTML-checkbox words: `on`/`checked`/`yes`/`true`/`1` are truthy, `off`/`unchecked`/`no`/`false`/`0` are falsey
+    HtmlForm,
+    /// The four-element defaults plus `enable`/`enabled`/`y` and `disable`/`disabled`/`n`
+    Extended,
+    /// Any nonzero integer is truthy and `0` is falsey
+    Numeric,
+}
+
+/// How a [`LexicalBoolParser`](./struct.LexicalBoolParser.html) treats unrecognized input
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Unknown or empty input is an [`Error::InvalidInput`](./enum.Error.html#variant.InvalidInput)
+    #[default]
+    Strict,
+    /// Any non-truthy token (including empty/whitespace-only input) is treated as `false`,
+    /// matching HTML-checkbox semantics where a missing or unrecognized value means "off"
+    Lenient,
+}
+
+impl Default for LexicalBoolParser {
+    fn default() -> Self {
+        Self {
+            true_values: TRUTHY_VALUES.iter().map(ToString::to_string).collect(),
+            false_values: FALSEY_VALUES.iter().map(ToString::to_string).collect(),
+            case_insensitive: true,
+            mode: ParseMode::Strict,
+            numeric: false,
+        }
+    }
+}
+
+impl LexicalBoolParser {
+    /// Create a parser seeded with the default [`TRUTHY_VALUES`](./constant.TRUTHY_VALUES.html)
+    /// and [`FALSEY_VALUES`](./constant.FALSEY_VALUES.html), matching case-insensitively
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the truth-y values this parser matches against
+    pub fn with_true_values<S: ToString>(mut self, values: impl IntoIterator<Item = S>) -> Self {
+        self.true_values = values.into_iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Replace the false-y values this parser matches against
+    pub fn with_false_values<S: ToString>(mut self, values: impl IntoIterator<Item = S>) -> Self {
+        self.false_values = values.into_iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Control whether matching ignores ASCII case (defaults to `true`)
+    pub fn case_insensitive(mut self, yes: bool) -> Self {
+        self.case_insensitive = yes;
+        self
+    }
+
+    /// Set how unrecognized input is handled (defaults to [`ParseMode::Strict`](./enum.ParseMode.html))
+    pub fn mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Replace this parser's vocabulary with one of the built-in [`Preset`](./enum.Preset.html)s
+    ///
+    /// ```rust
+    /// # use lexical_bool::{LexicalBoolParser, Preset};
+    /// let parser = LexicalBoolParser::new().with_preset(Preset::HtmlForm);
+    /// assert_eq!(parser.parse("on").unwrap(), true);
+    /// assert_eq!(parser.parse("off").unwrap(), false);
+    /// ```
+    pub fn with_preset(mut self, preset: Preset) -> Self {
+        match preset {
+            Preset::HtmlForm => {
+                self.true_values = ["on", "checked", "yes", "true", "1"]
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+                self.false_values = ["off", "unchecked", "no", "false", "0"]
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+                self.numeric = false;
+            }
+            Preset::Extended => {
+                self.true_values = TRUTHY_VALUES
+                    .iter()
+                    .chain(["enable", "enabled", "y"].iter())
+                    .map(ToString::to_string)
+                    .collect();
+                self.false_values = FALSEY_VALUES
+                    .iter()
+                    .chain(["disable", "disabled", "n"].iter())
+                    .map(ToString::to_string)
+                    .collect();
+                self.numeric = false;
+            }
+            Preset::Numeric => {
+                self.true_values.clear();
+                self.false_values.clear();
+                self.numeric = true;
+            }
+        }
+        self
+    }
+
+    /// Merge the vocabularies of `other` into this parser, with `other` winning on conflicts
+    ///
+    /// A value present in `other`'s truth-y set is removed from this parser's false-y set (and
+    /// vice-versa) before being added, so combining `defaults + app overrides + per-request
+    /// overrides` always resolves to the latest source's classification.
+    pub fn merge(&mut self, other: &Self) {
+        // words are compared ignoring ASCII case if either parser matches case-insensitively,
+        // so an override wins even when the conflicting word differs only in case
+        let ci = self.case_insensitive || other.case_insensitive;
+        let same = |a: &str, b: &str| if ci { a.eq_ignore_ascii_case(b) } else { a == b };
+
+        for value in &other.true_values {
+            self.false_values.retain(|v| !same(v, value));
+            if !self.true_values.iter().any(|v| same(v, value)) {
+                self.true_values.push(value.clone());
+            }
+        }
+        for value in &other.false_values {
+            self.true_values.retain(|v| !same(v, value));
+            if !self.false_values.iter().any(|v| same(v, value)) {
+                self.false_values.push(value.clone());
+            }
+        }
+        // only vocabularies are combined; `case_insensitive`/`mode` are left as configured on
+        // `self`. the numeric preset is additive, so it carries over if either parser had it.
+        self.numeric |= other.numeric;
+    }
+
+    /// Evaluate a boolean expression such as `"yes and not no"` or `"(t and f) or yes"`
+    ///
+    /// Tokens are split on whitespace and parentheses. Each word is either a truthy/falsey
+    /// literal (matched against this parser's value sets), one of the operators `and`/`or`/`not`
+    /// (case-insensitive), or a paren. `not` binds tightest, then `and`, then `or`; `not` is a
+    /// unary prefix and `and`/`or` are left-associative. A bare literal evaluates identically to
+    /// [`parse`](#method.parse); unbalanced parens, dangling operators and unknown tokens return
+    /// [`Error::ParseError`](./enum.Error.html#variant.ParseError) carrying the byte offset.
+    pub fn parse_expr(&self, s: &str) -> Result<LexicalBool, Error> {
+        let tokens = self.tokenize(s)?;
+        if tokens.is_empty() {
+            return Err(Error::ParseError {
+                position: 0,
+                message: "empty input".to_string(),
+            });
+        }
+        let mut parser = ExprParser { tokens: &tokens, pos: 0, end: s.len() };
+        let value = parser.expr()?;
+        if parser.pos != parser.tokens.len() {
+            let (_, position) = parser.tokens[parser.pos];
+            return Err(Error::ParseError {
+                position,
+                message: "unexpected trailing token".to_string(),
+            });
+        }
+        Ok(LexicalBool(value))
+    }
+
+    fn tokenize(&self, s: &str) -> Result<Vec<(Token, usize)>, Error> {
+        let mut tokens = Vec::new();
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let c = bytes[i];
+            if c.is_ascii_whitespace() {
+                i += 1;
+                continue;
+            }
+            if c == b'(' {
+                tokens.push((Token::LParen, i));
+                i += 1;
+                continue;
+            }
+            if c == b')' {
+                tokens.push((Token::RParen, i));
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < bytes.len()
+                && !bytes[i].is_ascii_whitespace()
+                && bytes[i] != b'('
+                && bytes[i] != b')'
+            {
+                i += 1;
+            }
+            let word = &s[start..i];
+            let token = if word.eq_ignore_ascii_case("and") {
+                Token::And
+            } else if word.eq_ignore_ascii_case("or") {
+                Token::Or
+            } else if word.eq_ignore_ascii_case("not") {
+                Token::Not
+            } else {
+                // classify against the value sets directly: the lenient-mode fallback must not
+                // silently turn an unknown word into `Lit(false)` here
+                match self.classify_literal(word) {
+                    Some(value) => Token::Lit(value),
+                    None => {
+                        return Err(Error::ParseError {
+                            position: start,
+                            message: format!("unknown token: {}", word),
+                        })
+                    }
+                }
+            };
+            tokens.push((token, start));
+        }
+        Ok(tokens)
+    }
+
+    /// Classify `s` as a known truthy/falsey literal, ignoring the [`ParseMode`] fallback
+    ///
+    /// Returns `None` for anything not explicitly in the value sets (or, when the numeric preset
+    /// is active, not a valid integer).
+    fn classify_literal(&self, s: &str) -> Option<bool> {
+        let matches = |values: &[String]| {
+            values.iter().any(|k| {
+                if self.case_insensitive {
+                    k.eq_ignore_ascii_case(s)
+                } else {
+                    k == s
+                }
+            })
+        };
+
+        if matches(&self.true_values) {
+            return Some(true);
+        }
+        if matches(&self.false_values) {
+            return Some(false);
+        }
+        if self.numeric {
+            if let Ok(n) = s.parse::<i128>() {
+                return Some(n != 0);
+            }
+        }
+        None
+    }
+
+    /// Parse a string into a [`LexicalBool`](./struct.LexicalBool.html) using this parser's values
+    pub fn parse(&self, s: &str) -> Result<LexicalBool, Error> {
+        if let Some(value) = self.classify_literal(s) {
+            return Ok(LexicalBool(value));
+        }
+        match self.mode {
+            ParseMode::Strict => Err(Error::InvalidInput(s.to_string())),
+            // unknown (and empty/whitespace-only) input falls back to "off"
+            ParseMode::Lenient => Ok(LexicalBool(false)),
+        }
+    }
+}
+
+/// A classified token produced by [`LexicalBoolParser::tokenize`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Token {
+    Lit(bool),
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+/// Recursive-descent / precedence-climbing evaluator over a stream of [`Token`]s.
+struct ExprParser<'a> {
+    tokens: &'a [(Token, usize)],
+    pos: usize,
+    end: usize,
+}
+
+impl ExprParser<'_> {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.pos).map(|&(t, _)| t)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.pos).map(|&(_, p)| p).unwrap_or(self.end)
+    }
+
+    // or_expr := and_expr ( "or" and_expr )*
+    fn expr(&mut self) -> Result<bool, Error> {
+        let mut value = self.and_expr()?;
+        while self.peek() == Some(Token::Or) {
+            self.pos += 1;
+            value |= self.and_expr()?;
+        }
+        Ok(value)
+    }
+
+    // and_expr := not_expr ( "and" not_expr )*
+    fn and_expr(&mut self) -> Result<bool, Error> {
+        let mut value = self.not_expr()?;
+        while self.peek() == Some(Token::And) {
+            self.pos += 1;
+            value &= self.not_expr()?;
+        }
+        Ok(value)
+    }
+
+    // not_expr := "not" not_expr | primary
+    fn not_expr(&mut self) -> Result<bool, Error> {
+        if self.peek() == Some(Token::Not) {
+            self.pos += 1;
+            return Ok(!self.not_expr()?);
+        }
+        self.primary()
+    }
+
+    // primary := "(" expr ")" | literal
+    fn primary(&mut self) -> Result<bool, Error> {
+        match self.peek() {
+            Some(Token::Lit(value)) => {
+                self.pos += 1;
+                Ok(value)
+            }
+            Some(Token::LParen) => {
+                self.pos += 1;
+                let value = self.expr()?;
+                if self.peek() != Some(Token::RParen) {
+                    return Err(Error::ParseError {
+                        position: self.position(),
+                        message: "unbalanced parentheses".to_string(),
+                    });
+                }
+                self.pos += 1;
+                Ok(value)
+            }
+            _ => Err(Error::ParseError {
+                position: self.position(),
+                message: "expected a literal or '('".to_string(),
+            }),
+        }
+    }
+}
+
+/// Build a parser from this thread's configured (or default) values.
+fn thread_parser() -> LexicalBoolParser {
+    let true_values = TRUE_VALUES
+        .with(|f| f.get_or_init(|| TRUTHY_VALUES.iter().map(ToString::to_string).collect()).clone());
+    let false_values = FALSE_VALUES
+        .with(|f| f.get_or_init(|| FALSEY_VALUES.iter().map(ToString::to_string).collect()).clone());
+    LexicalBoolParser {
+        true_values,
+        false_values,
+        ..LexicalBoolParser::default()
+    }
+}
+
 /// `LexicalBool` allows parsing truthy-like strings to a bool
 ///
 /// It can be `deref` (e.g. `*lb`) to get the bool, or compared to a bool (e.g. `lb == false`)
@@ -121,6 +503,26 @@ pub fn initialize_false_values<S: ToString>(values: impl IntoIterator<Item = S>)
 #[derive(Copy, Clone, Debug, PartialEq, Default)]
 pub struct LexicalBool(bool);
 
+impl LexicalBool {
+    /// Evaluate a boolean expression using this thread's configured (or default) values
+    ///
+    /// See [`LexicalBoolParser::parse_expr`](./struct.LexicalBoolParser.html#method.parse_expr)
+    /// for the grammar. A bare literal parses identically to
+    /// [`from_str`](https://doc.rust-lang.org/std/str/trait.FromStr.html).
+    pub fn parse_expr(s: &str) -> Result<LexicalBool, Error> {
+        thread_parser().parse_expr(s)
+    }
+
+    /// Parse leniently using this thread's configured (or default) values
+    ///
+    /// Any non-truthy token — including empty or whitespace-only input — is treated as `false`
+    /// rather than returning an error. See [`ParseMode::Lenient`](./enum.ParseMode.html).
+    pub fn from_str_lenient(s: &str) -> LexicalBool {
+        // lenient parsing never fails
+        thread_parser().mode(ParseMode::Lenient).parse(s).unwrap()
+    }
+}
+
 impl std::ops::Deref for LexicalBool {
     type Target = bool;
     fn deref(&self) -> &Self::Target {
@@ -137,24 +539,7 @@ impl PartialEq<bool> for LexicalBool {
 impl std::str::FromStr for LexicalBool {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let e = s.to_ascii_lowercase();
-        if TRUE_VALUES.with(|f| {
-            f.get_or_init(|| TRUTHY_VALUES.iter().map(ToString::to_string).collect())
-                .iter()
-                .any(|k| k == &e)
-        }) {
-            return Ok(LexicalBool(true));
-        }
-
-        if FALSE_VALUES.with(|f| {
-            f.get_or_init(|| FALSEY_VALUES.iter().map(ToString::to_string).collect())
-                .iter()
-                .any(|k| k == &e)
-        }) {
-            return Ok(LexicalBool(false));
-        }
-
-        Err(Error::InvalidInput(s.to_string()))
+        thread_parser().parse(s)
     }
 }
 
@@ -177,6 +562,16 @@ pub const FALSEY_VALUES: [&str; 4] = ["false", "f", "0", "no"];
 pub enum Error {
     /// Invalid input while parsing the string
     InvalidInput(String),
+    /// A malformed boolean expression while parsing with
+    /// [`parse_expr`](./struct.LexicalBoolParser.html#method.parse_expr)
+    ///
+    /// `position` is the byte offset into the input where the problem was found.
+    ParseError {
+        /// Byte offset of the offending token (or the end of input)
+        position: usize,
+        /// A human-readable description of the problem
+        message: String,
+    },
 }
 
 impl std::fmt::Display for Error {
@@ -198,12 +593,62 @@ impl std::fmt::Display for Error {
                         a
                     })
             ),
+            Error::ParseError { position, message } => {
+                write!(f, "parse error at byte {}: {}", position, message)
+            }
         }
     }
 }
 
 impl std::error::Error for Error {}
 
+/// `LexicalBool` serializes as a plain bool and deserializes from either a native boolean
+/// or a string run through the same truthy/falsey matching as
+/// [`FromStr`](https://doc.rust-lang.org/std/str/trait.FromStr.html).
+#[cfg(feature = "serde")]
+impl serde::Serialize for LexicalBool {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_bool(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LexicalBool {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct LexicalBoolVisitor;
+
+        impl serde::de::Visitor<'_> for LexicalBoolVisitor {
+            type Value = LexicalBool;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a boolean or a truthy/falsey string")
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(LexicalBool(value))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                value.parse().map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_any(LexicalBoolVisitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,7 +670,7 @@ mod tests {
 
     #[test]
     fn parse_custom_true() {
-        assert!(initialize_true_values(&["this is true", "yep", "YEP"]));
+        assert!(initialize_true_values(["this is true", "yep", "YEP"]));
         let inputs = &[
             ("this is true", true),
             ("yep", true),
@@ -241,9 +686,180 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parser_instance() {
+        let parser = LexicalBoolParser::new()
+            .with_true_values(["foo", "bar"])
+            .with_false_values(["baz", "qux"]);
+        let inputs = &[
+            ("foo", true),
+            ("BAR", true),
+            ("baz", false),
+            ("QUX", false),
+        ];
+        for &(input, ok) in inputs {
+            assert_eq!(parser.parse(input).unwrap(), ok);
+        }
+        assert_eq!(parser.parse("nope"), Err(Error::InvalidInput("nope".to_string())));
+    }
+
+    #[test]
+    fn parser_case_sensitive() {
+        let parser = LexicalBoolParser::new()
+            .with_true_values(["Yes"])
+            .case_insensitive(false);
+        assert_eq!(parser.parse("Yes").unwrap(), true);
+        assert_eq!(parser.parse("yes"), Err(Error::InvalidInput("yes".to_string())));
+    }
+
+    #[test]
+    fn parser_merge_later_wins() {
+        let mut base = LexicalBoolParser::new()
+            .with_true_values(["on"])
+            .with_false_values(["off"]);
+        let overrides = LexicalBoolParser::new()
+            .with_true_values(["off"])
+            .with_false_values(["nope"]);
+        base.merge(&overrides);
+        // `off` was false-y in the base but truth-y in the overrides, so the override wins
+        assert_eq!(base.parse("off").unwrap(), true);
+        assert_eq!(base.parse("on").unwrap(), true);
+        assert_eq!(base.parse("nope").unwrap(), false);
+    }
+
+    #[test]
+    fn parser_merge_case_insensitive_conflict() {
+        let mut base = LexicalBoolParser::new().with_true_values(["YES"]);
+        let overrides = LexicalBoolParser::new().with_false_values(["yes"]);
+        base.merge(&overrides);
+        // the stale "YES" truthy entry must be removed so the override wins
+        assert_eq!(base.parse("yes").unwrap(), false);
+    }
+
+    #[test]
+    fn preset_html_form() {
+        let parser = LexicalBoolParser::new().with_preset(Preset::HtmlForm);
+        let inputs = &[
+            ("on", true),
+            ("checked", true),
+            ("1", true),
+            ("off", false),
+            ("unchecked", false),
+            ("0", false),
+        ];
+        for &(input, ok) in inputs {
+            assert_eq!(parser.parse(input).unwrap(), ok);
+        }
+    }
+
+    #[test]
+    fn preset_extended() {
+        let parser = LexicalBoolParser::new().with_preset(Preset::Extended);
+        assert_eq!(parser.parse("enabled").unwrap(), true);
+        assert_eq!(parser.parse("y").unwrap(), true);
+        assert_eq!(parser.parse("disabled").unwrap(), false);
+        assert_eq!(parser.parse("n").unwrap(), false);
+        // defaults are still present
+        assert_eq!(parser.parse("true").unwrap(), true);
+    }
+
+    #[test]
+    fn preset_numeric() {
+        let parser = LexicalBoolParser::new().with_preset(Preset::Numeric);
+        assert_eq!(parser.parse("0").unwrap(), false);
+        assert_eq!(parser.parse("1").unwrap(), true);
+        assert_eq!(parser.parse("42").unwrap(), true);
+        assert_eq!(parser.parse("-3").unwrap(), true);
+        assert_eq!(
+            parser.parse("nope"),
+            Err(Error::InvalidInput("nope".to_string()))
+        );
+    }
+
+    #[test]
+    fn lenient_mode() {
+        let parser = LexicalBoolParser::new().mode(ParseMode::Lenient);
+        assert_eq!(parser.parse("yes").unwrap(), true);
+        assert_eq!(parser.parse("no").unwrap(), false);
+        assert_eq!(parser.parse("whatever").unwrap(), false);
+        assert_eq!(parser.parse("").unwrap(), false);
+        assert_eq!(parser.parse("   ").unwrap(), false);
+    }
+
+    #[test]
+    fn from_str_lenient_fallback() {
+        assert_eq!(LexicalBool::from_str_lenient("1"), true);
+        assert_eq!(LexicalBool::from_str_lenient("bogus"), false);
+        assert_eq!(LexicalBool::from_str_lenient(""), false);
+    }
+
+    #[test]
+    fn parse_expr_basic() {
+        let inputs = &[
+            ("yes", true),
+            ("yes and not no", true),
+            ("1 or false", true),
+            ("(t and f) or yes", true),
+            ("t and f", false),
+            ("not (yes or no)", false),
+        ];
+        for &(input, ok) in inputs {
+            assert_eq!(LexicalBool::parse_expr(input).unwrap(), ok, "{}", input);
+        }
+    }
+
+    #[test]
+    fn parse_expr_errors() {
+        assert!(matches!(
+            LexicalBool::parse_expr("(yes and no"),
+            Err(Error::ParseError { .. })
+        ));
+        assert!(matches!(
+            LexicalBool::parse_expr("yes and"),
+            Err(Error::ParseError { .. })
+        ));
+        assert!(matches!(
+            LexicalBool::parse_expr("maybe"),
+            Err(Error::ParseError { .. })
+        ));
+        assert!(matches!(
+            LexicalBool::parse_expr(""),
+            Err(Error::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_expr_unknown_token_in_lenient_mode() {
+        // lenient mode must not swallow unknown tokens inside an expression
+        let parser = LexicalBoolParser::new().mode(ParseMode::Lenient);
+        assert!(matches!(
+            parser.parse_expr("yes and maybe"),
+            Err(Error::ParseError { .. })
+        ));
+        // known literals still evaluate
+        assert_eq!(parser.parse_expr("yes and not no").unwrap(), true);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let inputs = &[
+            ("true", true),
+            ("\"yes\"", true),
+            ("\"0\"", false),
+            ("\"no\"", false),
+            ("false", false),
+        ];
+        for &(json, ok) in inputs {
+            let lb: LexicalBool = serde_json::from_str(json).unwrap();
+            assert_eq!(lb, ok);
+            assert_eq!(serde_json::to_string(&lb).unwrap(), ok.to_string());
+        }
+    }
+
     #[test]
     fn parse_custom_false() {
-        assert!(initialize_false_values(&["this is false", "nope", "NOPE"]));
+        assert!(initialize_false_values(["this is false", "nope", "NOPE"]));
         let inputs = &[
             ("this is false", false),
             ("nope", false),